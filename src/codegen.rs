@@ -0,0 +1,239 @@
+use itertools::Itertools;
+
+use crate::schema::types::{Property, PropertyType, Schema};
+
+/// Generates one `struct` (or untagged `enum`, for a `schema-group`) per schema,
+/// together with any nested `enum`s a `OneOf` property needed along the way.
+pub fn schemas_to_rust(schemas: &[Schema]) -> String {
+  schemas.iter().map(schema_to_rust).join("\n\n")
+}
+
+fn schema_to_rust(schema: &Schema) -> String {
+  if !schema.group_members.is_empty() {
+    return group_to_rust(schema);
+  }
+
+  let type_name = struct_name(&schema.schema_name);
+
+  let fields_and_defs: Vec<(String, Vec<String>)> = schema
+    .properties
+    .iter()
+    .sorted_by_key(|(name, _)| (*name).clone())
+    .map(|(name, property)| property_to_field(&type_name, name, property))
+    .collect();
+
+  let fields = fields_and_defs.iter().map(|(field, _)| field.clone()).join("\n");
+  let extra_defs = fields_and_defs.into_iter().flat_map(|(_, defs)| defs).join("\n\n");
+
+  let struct_def = format!(
+    "#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]\npub struct {type_name} {{\n{fields}\n}}",
+    type_name = type_name,
+    fields = fields,
+  );
+
+  if extra_defs.is_empty() {
+    struct_def
+  } else {
+    format!("{}\n\n{}", extra_defs, struct_def)
+  }
+}
+
+fn group_to_rust(schema: &Schema) -> String {
+  let variants = schema
+    .group_members
+    .iter()
+    .map(|member| format!("  {variant}({variant}),", variant = struct_name(member)))
+    .join("\n");
+
+  format!(
+    "#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]\n#[serde(untagged)]\npub enum {type_name} {{\n{variants}\n}}",
+    type_name = struct_name(&schema.schema_name),
+    variants = variants,
+  )
+}
+
+fn property_to_field(type_name: &str, name: &str, property: &Property) -> (String, Vec<String>) {
+  let enum_name_hint = format!("{}{}", type_name, struct_name(name));
+  let (rust_type, extra_defs) = property_type_to_rust(&enum_name_hint, &property.type_name);
+
+  // `convert_prop` sets `list` exactly when the type string starts with `[`,
+  // in which case `type_name` is already `ArrayOf`/`Tuple` and `rust_type`
+  // above is already `Vec<_>`/a tuple. Wrapping in another `Vec<_>` here
+  // would double it up.
+  let rust_type = if property.list && !matches!(property.type_name, PropertyType::ArrayOf(_) | PropertyType::Tuple(_)) {
+    format!("Vec<{}>", rust_type)
+  } else {
+    rust_type
+  };
+  let rust_type = if property.required {
+    rust_type
+  } else {
+    format!("Option<{}>", rust_type)
+  };
+
+  let docs = property.docs.lines().map(|line| format!("  /// {}\n", line)).join("");
+
+  let field = format!(
+    "{docs}  #[serde(rename = \"{original}\")]\n  pub {field_name}: {rust_type},",
+    docs = docs,
+    original = name,
+    field_name = field_name(name),
+    rust_type = rust_type,
+  );
+
+  (field, extra_defs)
+}
+
+fn property_type_to_rust(enum_name_hint: &str, property_type: &PropertyType) -> (String, Vec<String>) {
+  match property_type {
+    // A bare literal constant carries no data of its own; it is only ever
+    // meaningful as a `OneOf` discriminant, handled below.
+    PropertyType::Constant(_) => ("()".to_string(), vec![]),
+
+    PropertyType::Ref(name) => (struct_name(name), vec![]),
+
+    PropertyType::ArrayOf(item_type) => {
+      let (item_rust_type, defs) = property_type_to_rust(enum_name_hint, item_type);
+      (format!("Vec<{}>", item_rust_type), defs)
+    }
+
+    PropertyType::Dict(_key_type, value_type) => {
+      let (value_rust_type, defs) = property_type_to_rust(enum_name_hint, value_type);
+      (format!("std::collections::HashMap<String, {}>", value_rust_type), defs)
+    }
+
+    PropertyType::Tuple(item_types) => {
+      let (item_rust_types, defs): (Vec<String>, Vec<Vec<String>>) = item_types
+        .iter()
+        .map(|item_type| property_type_to_rust(enum_name_hint, item_type))
+        .unzip();
+      (format!("({})", item_rust_types.join(", ")), defs.into_iter().flatten().collect())
+    }
+
+    PropertyType::String => ("String".to_string(), vec![]),
+    PropertyType::Number => ("f64".to_string(), vec![]),
+    PropertyType::Boolean => ("bool".to_string(), vec![]),
+    PropertyType::Int => ("i64".to_string(), vec![]),
+    PropertyType::Null => ("()".to_string(), vec![]),
+
+    // The grammar only ever produces a union with two or more variants, but
+    // collapse defensively rather than emit a pointless one-variant wrapper
+    // enum for any `OneOf` that does end up with a single variant.
+    PropertyType::OneOf(variants) if variants.len() == 1 => property_type_to_rust(enum_name_hint, &variants[0]),
+
+    PropertyType::OneOf(variants) => {
+      let enum_name = struct_name(enum_name_hint);
+      let (variants, nested_defs): (Vec<String>, Vec<Vec<String>>) = variants
+        .iter()
+        .enumerate()
+        .map(|(index, variant)| oneof_variant_to_rust(&format!("{}Variant{}", enum_name, index), variant))
+        .unzip();
+      let variants = variants.join("\n");
+
+      let def = format!(
+        "#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]\n#[serde(untagged)]\npub enum {enum_name} {{\n{variants}\n}}",
+        enum_name = enum_name,
+        variants = variants,
+      );
+
+      let mut defs: Vec<String> = nested_defs.into_iter().flatten().collect();
+      defs.push(def);
+      (enum_name, defs)
+    }
+  }
+}
+
+fn oneof_variant_to_rust(enum_name_hint: &str, property_type: &PropertyType) -> (String, Vec<String>) {
+  match property_type {
+    PropertyType::Constant(value) => (format!("  {},", struct_name(value)), vec![]),
+    other => {
+      let (rust_type, defs) = property_type_to_rust(enum_name_hint, other);
+      (format!("  {variant}({rust_type}),", variant = struct_name(&rust_type), rust_type = rust_type), defs)
+    }
+  }
+}
+
+fn struct_name(name: &str) -> String {
+  let pascal_case: String = name
+    .split(|c: char| !c.is_alphanumeric())
+    .filter(|part| !part.is_empty())
+    .map(|part| {
+      let mut chars = part.chars();
+      match chars.next() {
+        Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+        None => String::new(),
+      }
+    })
+    .collect();
+
+  // A name that is empty or starts with a digit (e.g. a numeric `Constant`
+  // such as `` `2` ``) is not a valid Rust identifier on its own.
+  match pascal_case.chars().next() {
+    Some(first) if first.is_ascii_digit() => format!("_{}", pascal_case),
+    None => "_".to_string(),
+    _ => pascal_case,
+  }
+}
+
+fn field_name(name: &str) -> String {
+  name.replace(['-', ' '], "_").to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::HashMap;
+
+  use super::*;
+
+  #[test]
+  fn a_list_of_scalars_maps_to_a_plain_vec_without_doubling_up() {
+    let schema = Schema {
+      schema_name: "holder".to_string(),
+      properties: HashMap::from([(
+        "inputs".to_string(),
+        Property {
+          required: true,
+          docs: String::new(),
+          // What the grammar now produces for a `[string]`-typed attribute.
+          type_name: PropertyType::ArrayOf(Box::new(PropertyType::String)),
+          list: true,
+        },
+      )]),
+      is_group_member: false,
+      group_members: vec![],
+    };
+
+    let rust = schema_to_rust(&schema);
+
+    assert!(rust.contains("Vec<String>"), "expected a plain Vec<String>, got:\n{}", rust);
+    assert!(!rust.contains("Vec<Vec<"), "list-ness was counted twice, got:\n{}", rust);
+    assert!(!rust.contains("enum"), "a scalar list shouldn't need a wrapper enum, got:\n{}", rust);
+  }
+
+  #[test]
+  fn a_oneof_with_two_variants_generates_an_untagged_enum() {
+    let (rust_type, defs) = property_type_to_rust("HolderField", &PropertyType::OneOf(vec![PropertyType::String, PropertyType::Int]));
+
+    assert_eq!(rust_type, "HolderField");
+    assert_eq!(defs.len(), 1);
+    assert!(defs[0].contains("enum HolderField"));
+    assert!(defs[0].contains("String(String)"));
+    assert!(defs[0].contains("I64(i64)"), "expected an i64-backed variant, got:\n{}", defs[0]);
+  }
+
+  #[test]
+  fn a_constant_oneof_variant_generates_a_unit_marker() {
+    let (_, defs) = property_type_to_rust(
+      "HolderField",
+      &PropertyType::OneOf(vec![PropertyType::Constant("get".to_string()), PropertyType::Constant("put".to_string())]),
+    );
+
+    assert!(defs[0].contains("Get,"));
+    assert!(defs[0].contains("Put,"));
+  }
+
+  #[test]
+  fn a_numeric_constant_variant_is_a_valid_rust_identifier() {
+    assert_eq!(struct_name("2"), "_2");
+  }
+}
@@ -9,6 +9,19 @@ pub fn to_jsonschemas(doc: &LitDocument) -> Vec<Schema> {
   collect_schemas(doc)
 }
 
+/// Normalizes a schema name exactly the way `collect_schemas` does, so that a
+/// reference written as e.g. `` `some-resource` `` resolves to the schema
+/// emitted for `some resource` or `some-resource`.
+pub(crate) fn normalize_schema_name(name: &str) -> String {
+  name
+    .replace("`", "_")
+    .replace("-", "_")
+    .replace(" ", "_")
+    .replace("__", "_")
+    .trim_start_matches("_")
+    .to_string()
+}
+
 fn extend_child_properties(
   child_schemas: &mut Vec<Schema>,
   attributes: &HashMap<String, Property>,
@@ -33,14 +46,7 @@ fn collect_schemas(doc: &LitDocument) -> Vec<Schema> {
       LitNode::Fn(schema, args) if (schema == "schema") || (schema == "schema-group") => {
         let mut found_schemas: Vec<Schema> = vec![];
 
-        let schema_name = text_to_markdown(&args[0])
-          .trim()
-          .replace("`", "_")
-          .replace("-", "_")
-          .replace(" ", "_")
-          .replace("__", "_")
-          .trim_start_matches("_")
-          .to_string();
+        let schema_name = normalize_schema_name(text_to_markdown(&args[0]).trim());
 
         log::debug!("In schema {}", schema_name);
 
@@ -148,14 +154,38 @@ peg::parser! {
     pub rule lit_type() -> PropertyType
       = union_type() / non_union_type()
 
+    // A trailing `?` marks the type nullable/optional, lowering to a union
+    // with `null` rather than a distinct `PropertyType` of its own.
     rule non_union_type() -> PropertyType
-      = array_type() / dictionary_type() / constant_type() / ref_type()
+      = inner_type:base_type() optional:"?"? {
+        match optional {
+          Some(_) => PropertyType::OneOf(vec![inner_type, PropertyType::Null]),
+          None => inner_type,
+        }
+      }
+
+    rule base_type() -> PropertyType
+      = array_type() / tuple_type() / dictionary_type() / constant_type() / scalar_type() / ref_type()
 
     rule array_type() -> PropertyType
       = "[" inner_type:lit_type() "]" { PropertyType::ArrayOf(Box::new(inner_type)) }
 
-    rule union_type() -> PropertyType =
-      inner_types:(non_union_type() ++ (_ "|" _)) { PropertyType::OneOf(inner_types) }
+    rule tuple_type() -> PropertyType
+      = "[" _ first:lit_type() rest:(_ "," _ t:lit_type() { t })+ _ "]" {
+        let mut items = vec![first];
+        items.extend(rest);
+        PropertyType::Tuple(items)
+      }
+
+    // `++` matches one-or-more, so without a separate leading element a lone
+    // type (no `|` at all) would also match here and get wrapped in a
+    // singleton `OneOf`. Requiring at least one `|` keeps a bare type bare.
+    rule union_type() -> PropertyType
+      = first:non_union_type() rest:(_ "|" _ t:non_union_type() { t })+ {
+        let mut variants = vec![first];
+        variants.extend(rest);
+        PropertyType::OneOf(variants)
+      }
 
     rule _ = [' ' | '\n']*;
 
@@ -166,16 +196,27 @@ peg::parser! {
       = name:$(['a'..='z' | 'A'..='Z' | '_']+) { String::from(name) }
 
     rule dictionary_type() -> PropertyType
-      = "{" _ key_or_value_string() _ ":" _ key_or_value_string() "}" { PropertyType::Dict }
+      = "{" _ key:lit_type() _ ":" _ value:lit_type() _ "}" {
+        PropertyType::Dict(Box::new(key), Box::new(value))
+      }
 
     rule constant_type() -> PropertyType
       = "`" value:key_or_value_string() "`" { PropertyType::Constant(value) }
 
+    rule scalar_type() -> PropertyType
+      = name:type_identifier() {?
+        match name.as_str() {
+          "string" => Ok(PropertyType::String),
+          "number" => Ok(PropertyType::Number),
+          "boolean" => Ok(PropertyType::Boolean),
+          "int" => Ok(PropertyType::Int),
+          _ => Err("not a scalar keyword"),
+        }
+      }
+
     rule ref_type() -> PropertyType
       = name:key_or_value_string() {
-        PropertyType::Ref(
-          if name.contains(".") { "string".to_string() } else { name }
-        )
+        if name.contains(".") { PropertyType::String } else { PropertyType::Ref(name) }
       }
 
 
@@ -306,3 +347,56 @@ pub fn raw_text(nodes: &Vec<LitNode>) -> String {
     })
     .collect::<String>()
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn a_lone_type_is_not_wrapped_in_a_singleton_union() {
+    assert_eq!(parse_type("string"), PropertyType::String);
+    assert_eq!(parse_type("some_ref"), PropertyType::Ref("some_ref".to_string()));
+  }
+
+  #[test]
+  fn array_of_a_lone_type_is_not_wrapped_in_a_singleton_union() {
+    assert_eq!(parse_type("[string]"), PropertyType::ArrayOf(Box::new(PropertyType::String)));
+  }
+
+  #[test]
+  fn union_requires_at_least_one_pipe() {
+    assert_eq!(
+      parse_type("string|number"),
+      PropertyType::OneOf(vec![PropertyType::String, PropertyType::Number])
+    );
+  }
+
+  #[test]
+  fn optional_marker_lowers_to_a_nullable_union() {
+    assert_eq!(
+      parse_type("string?"),
+      PropertyType::OneOf(vec![PropertyType::String, PropertyType::Null])
+    );
+  }
+
+  #[test]
+  fn tuple_type_parses_each_element() {
+    assert_eq!(
+      parse_type("[string, number]"),
+      PropertyType::Tuple(vec![PropertyType::String, PropertyType::Number])
+    );
+  }
+
+  #[test]
+  fn dict_type_preserves_key_and_value_types() {
+    assert_eq!(
+      parse_type("{string:number}"),
+      PropertyType::Dict(Box::new(PropertyType::String), Box::new(PropertyType::Number))
+    );
+  }
+
+  #[test]
+  fn dotted_type_names_are_a_plain_string_not_an_unresolvable_ref() {
+    assert_eq!(parse_type("time.Duration"), PropertyType::String);
+  }
+}
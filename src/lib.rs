@@ -0,0 +1,5 @@
+pub mod codegen;
+pub mod convert;
+pub mod lit;
+pub mod schema;
+pub mod validate;
@@ -0,0 +1,9 @@
+/// A LIT document is a sequence of sibling nodes.
+pub type LitDocument = Vec<LitNode>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum LitNode {
+  Text(String),
+  Fn(String, Vec<LitDocument>),
+  Comment(String),
+}
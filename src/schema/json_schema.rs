@@ -0,0 +1,207 @@
+use serde_json::{json, Map, Value};
+
+use super::types::{Property, PropertyType, Schema};
+
+const JSON_SCHEMA_DRAFT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Serializes a single collected `Schema` into a draft 2020-12 JSON Schema document.
+pub fn schema_to_json_schema(schema: &Schema) -> Value {
+  if !schema.group_members.is_empty() {
+    return group_schema_to_json_schema(schema);
+  }
+
+  let mut properties = Map::new();
+  let mut required: Vec<String> = vec![];
+
+  for (name, property) in &schema.properties {
+    if property.required {
+      required.push(name.clone());
+    }
+    properties.insert(name.clone(), property_to_json_schema(property));
+  }
+
+  required.sort();
+
+  json!({
+    "$schema": JSON_SCHEMA_DRAFT,
+    "title": schema.schema_name,
+    "type": "object",
+    "properties": properties,
+    "required": required,
+  })
+}
+
+// Mirrors `validate::validate_group` and `codegen::group_to_rust`: a
+// `schema-group` schema doesn't describe its own properties, it matches
+// exactly one of its `group_members`.
+fn group_schema_to_json_schema(schema: &Schema) -> Value {
+  let members: Vec<Value> = schema.group_members.iter().map(|member| json!({ "$ref": member })).collect();
+
+  json!({
+    "$schema": JSON_SCHEMA_DRAFT,
+    "title": schema.schema_name,
+    "oneOf": members,
+  })
+}
+
+fn property_to_json_schema(property: &Property) -> Value {
+  let inner = property_type_to_json_schema(&property.type_name);
+
+  // As in `codegen::property_to_field`: a `[...]`-typed property is already
+  // `ArrayOf`/`Tuple`, so only wrap types that don't carry list-ness already.
+  let mut value = if property.list && !matches!(property.type_name, PropertyType::ArrayOf(_) | PropertyType::Tuple(_)) {
+    json!({ "type": "array", "items": inner })
+  } else {
+    inner
+  };
+
+  if let Value::Object(fields) = &mut value {
+    fields.insert("description".to_string(), json!(property.docs));
+  }
+
+  value
+}
+
+fn property_type_to_json_schema(property_type: &PropertyType) -> Value {
+  match property_type {
+    PropertyType::Constant(value) => json!({ "const": value }),
+
+    // A `Ref` is serialized as an opaque placeholder until the resolution pass
+    // (see `schema::resolve`) rewrites it into a `$ref` against `$defs`.
+    PropertyType::Ref(name) => json!({ "$ref": name }),
+
+    PropertyType::OneOf(variants) if variants.iter().all(is_constant) => {
+      let values: Vec<&String> = variants
+        .iter()
+        .map(|variant| match variant {
+          PropertyType::Constant(value) => value,
+          _ => unreachable!("checked by is_constant above"),
+        })
+        .collect();
+      json!({ "enum": values })
+    }
+
+    PropertyType::OneOf(variants) => {
+      let variants: Vec<Value> = variants.iter().map(property_type_to_json_schema).collect();
+      json!({ "oneOf": variants })
+    }
+
+    PropertyType::ArrayOf(item_type) => json!({
+      "type": "array",
+      "items": property_type_to_json_schema(item_type),
+    }),
+
+    PropertyType::Dict(_key_type, value_type) => json!({
+      "type": "object",
+      "additionalProperties": property_type_to_json_schema(value_type),
+    }),
+
+    PropertyType::Tuple(item_types) => json!({
+      "type": "array",
+      "prefixItems": item_types.iter().map(property_type_to_json_schema).collect::<Vec<_>>(),
+      "minItems": item_types.len(),
+      "maxItems": item_types.len(),
+    }),
+
+    PropertyType::String => json!({ "type": "string" }),
+    PropertyType::Number => json!({ "type": "number" }),
+    PropertyType::Boolean => json!({ "type": "boolean" }),
+    PropertyType::Int => json!({ "type": "integer" }),
+    PropertyType::Null => json!({ "type": "null" }),
+  }
+}
+
+fn is_constant(property_type: &PropertyType) -> bool {
+  matches!(property_type, PropertyType::Constant(_))
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::HashMap;
+
+  use super::*;
+
+  fn property(type_name: PropertyType) -> Property {
+    Property {
+      required: false,
+      docs: String::new(),
+      type_name,
+      list: false,
+    }
+  }
+
+  #[test]
+  fn a_union_of_constants_collapses_into_an_enum() {
+    let variant = PropertyType::OneOf(vec![
+      PropertyType::Constant("get".to_string()),
+      PropertyType::Constant("put".to_string()),
+    ]);
+
+    assert_eq!(property_type_to_json_schema(&variant), json!({ "enum": ["get", "put"] }));
+  }
+
+  #[test]
+  fn a_mixed_union_serializes_as_oneof() {
+    let variant = PropertyType::OneOf(vec![PropertyType::String, PropertyType::Number]);
+
+    assert_eq!(
+      property_type_to_json_schema(&variant),
+      json!({ "oneOf": [{ "type": "string" }, { "type": "number" }] })
+    );
+  }
+
+  #[test]
+  fn dict_serializes_as_additional_properties_of_the_value_type() {
+    let dict = PropertyType::Dict(Box::new(PropertyType::String), Box::new(PropertyType::Int));
+
+    assert_eq!(
+      property_type_to_json_schema(&dict),
+      json!({ "type": "object", "additionalProperties": { "type": "integer" } })
+    );
+  }
+
+  #[test]
+  fn tuple_serializes_as_an_array_with_positional_prefix_items() {
+    let tuple = PropertyType::Tuple(vec![PropertyType::String, PropertyType::Boolean]);
+
+    assert_eq!(
+      property_type_to_json_schema(&tuple),
+      json!({
+        "type": "array",
+        "prefixItems": [{ "type": "string" }, { "type": "boolean" }],
+        "minItems": 2,
+        "maxItems": 2,
+      })
+    );
+  }
+
+  #[test]
+  fn required_attributes_populate_the_object_level_required_array() {
+    let schema = Schema {
+      schema_name: "step".to_string(),
+      properties: HashMap::from([
+        ("name".to_string(), Property { required: true, ..property(PropertyType::String) }),
+        ("timeout".to_string(), property(PropertyType::String)),
+      ]),
+      is_group_member: false,
+      group_members: vec![],
+    };
+
+    assert_eq!(schema_to_json_schema(&schema)["required"], json!(["name"]));
+  }
+
+  #[test]
+  fn a_schema_group_serializes_as_a_oneof_of_its_members() {
+    let schema = Schema {
+      schema_name: "step".to_string(),
+      properties: HashMap::new(),
+      is_group_member: true,
+      group_members: vec!["get_step".to_string(), "put_step".to_string()],
+    };
+
+    assert_eq!(
+      schema_to_json_schema(&schema)["oneOf"],
+      json!([{ "$ref": "get_step" }, { "$ref": "put_step" }])
+    );
+  }
+}
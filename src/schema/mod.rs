@@ -0,0 +1,3 @@
+pub mod json_schema;
+pub mod resolve;
+pub mod types;
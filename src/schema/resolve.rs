@@ -0,0 +1,144 @@
+use std::collections::HashMap;
+
+use serde_json::{json, Map, Value};
+
+use crate::convert::normalize_schema_name;
+
+use super::json_schema::schema_to_json_schema;
+use super::types::{PropertyType, Schema};
+
+/// A structured failure for a schema-level reference whose target schema is
+/// absent from the collected schemas.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RefError {
+  MissingSchemaRef { referenced_by: String, target: String },
+}
+
+/// Resolves every `PropertyType::Ref` against the collected schemas and
+/// assembles a single document with a top-level `$defs` section. Returns the
+/// full list of dangling references instead of the document if any `Ref`
+/// target is missing.
+pub fn schemas_to_json_schema_document(schemas: &[Schema]) -> Result<Value, Vec<RefError>> {
+  let errors = find_dangling_refs(schemas);
+  if !errors.is_empty() {
+    return Err(errors);
+  }
+
+  let defs: Map<String, Value> = schemas
+    .iter()
+    .map(|schema| (schema.schema_name.clone(), resolve_refs_in_document(schema_to_json_schema(schema))))
+    .collect();
+
+  Ok(json!({ "$defs": defs }))
+}
+
+fn find_dangling_refs(schemas: &[Schema]) -> Vec<RefError> {
+  let by_name: HashMap<&String, &Schema> = schemas.iter().map(|s| (&s.schema_name, s)).collect();
+
+  schemas
+    .iter()
+    .flat_map(|schema| {
+      schema
+        .properties
+        .values()
+        .flat_map(|property| find_dangling_refs_in_type(&property.type_name, &by_name))
+        .map(|target| RefError::MissingSchemaRef {
+          referenced_by: schema.schema_name.clone(),
+          target,
+        })
+        .collect::<Vec<_>>()
+    })
+    .collect()
+}
+
+fn find_dangling_refs_in_type(property_type: &PropertyType, by_name: &HashMap<&String, &Schema>) -> Vec<String> {
+  match property_type {
+    PropertyType::Ref(name) => {
+      let normalized = normalize_schema_name(name);
+      if by_name.contains_key(&normalized) {
+        vec![]
+      } else {
+        vec![normalized]
+      }
+    }
+    PropertyType::Constant(_) => vec![],
+    PropertyType::OneOf(variants) => variants
+      .iter()
+      .flat_map(|variant| find_dangling_refs_in_type(variant, by_name))
+      .collect(),
+    PropertyType::ArrayOf(item_type) => find_dangling_refs_in_type(item_type, by_name),
+    PropertyType::Dict(key_type, value_type) => find_dangling_refs_in_type(key_type, by_name)
+      .into_iter()
+      .chain(find_dangling_refs_in_type(value_type, by_name))
+      .collect(),
+    PropertyType::Tuple(item_types) => item_types
+      .iter()
+      .flat_map(|item_type| find_dangling_refs_in_type(item_type, by_name))
+      .collect(),
+    PropertyType::String | PropertyType::Number | PropertyType::Boolean | PropertyType::Int | PropertyType::Null => vec![],
+  }
+}
+
+/// Rewrites the `{"$ref": "<name>", ...}` placeholders left by `json_schema`
+/// into proper `$defs`-relative JSON Pointers, normalizing the target name
+/// first. A `$ref` key is only ever written by `json_schema` as such a
+/// placeholder, so its presence alone identifies the object, regardless of
+/// the sibling keys (e.g. `description`) `json_schema` added next to it.
+fn resolve_refs_in_document(value: Value) -> Value {
+  match value {
+    Value::Object(mut fields) => {
+      if let Some(Value::String(name)) = fields.get("$ref").cloned() {
+        fields.insert("$ref".to_string(), json!(format!("#/$defs/{}", normalize_schema_name(&name))));
+        return Value::Object(fields);
+      }
+      for (_, field_value) in fields.iter_mut() {
+        *field_value = resolve_refs_in_document(field_value.take());
+      }
+      Value::Object(fields)
+    }
+    Value::Array(items) => Value::Array(items.into_iter().map(resolve_refs_in_document).collect()),
+    other => other,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::HashMap;
+
+  use super::*;
+  use crate::schema::types::Property;
+
+  fn schema(name: &str, properties: HashMap<String, Property>) -> Schema {
+    Schema {
+      schema_name: name.to_string(),
+      properties,
+      is_group_member: false,
+      group_members: vec![],
+    }
+  }
+
+  fn property(type_name: PropertyType) -> Property {
+    Property {
+      required: true,
+      docs: "a property".to_string(),
+      type_name,
+      list: false,
+    }
+  }
+
+  #[test]
+  fn rewrites_plain_ref_property_despite_the_sibling_description_key() {
+    let other = schema("other", HashMap::new());
+    let holder = schema(
+      "holder",
+      HashMap::from([("thing".to_string(), property(PropertyType::Ref("other".to_string())))]),
+    );
+
+    let document = schemas_to_json_schema_document(&[other, holder]).expect("refs should resolve");
+
+    assert_eq!(
+      document["$defs"]["holder"]["properties"]["thing"]["$ref"],
+      json!("#/$defs/other")
+    );
+  }
+}
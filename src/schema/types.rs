@@ -0,0 +1,32 @@
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Schema {
+  pub schema_name: String,
+  pub properties: HashMap<String, Property>,
+  pub is_group_member: bool,
+  pub group_members: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Property {
+  pub required: bool,
+  pub docs: String,
+  pub type_name: PropertyType,
+  pub list: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PropertyType {
+  Ref(String),
+  Constant(String),
+  OneOf(Vec<PropertyType>),
+  ArrayOf(Box<PropertyType>),
+  Dict(Box<PropertyType>, Box<PropertyType>),
+  Tuple(Vec<PropertyType>),
+  String,
+  Number,
+  Boolean,
+  Int,
+  Null,
+}
@@ -0,0 +1,280 @@
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+use crate::convert::normalize_schema_name;
+use crate::schema::types::{Property, PropertyType, Schema};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+  Error,
+  Warning,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+  pub path: String,
+  pub severity: Severity,
+  pub message: String,
+}
+
+impl Diagnostic {
+  fn error(path: &str, message: String) -> Diagnostic {
+    Diagnostic {
+      path: path.to_string(),
+      severity: Severity::Error,
+      message,
+    }
+  }
+
+  fn warning(path: &str, message: String) -> Diagnostic {
+    Diagnostic {
+      path: path.to_string(),
+      severity: Severity::Warning,
+      message,
+    }
+  }
+}
+
+/// Validates a parsed Concourse pipeline document (a YAML or JSON mapping,
+/// represented as `serde_json::Value`) against one of the schemas produced by
+/// `to_jsonschemas`, using `schemas` to dispatch `Ref`s and group members.
+pub fn validate_document(schema: &Schema, schemas: &HashMap<String, Schema>, document: &Value) -> Vec<Diagnostic> {
+  validate_against_schema(schema, schemas, document, "")
+}
+
+fn validate_against_schema(schema: &Schema, schemas: &HashMap<String, Schema>, document: &Value, path: &str) -> Vec<Diagnostic> {
+  if !schema.group_members.is_empty() {
+    return validate_group(schema, schemas, document, path);
+  }
+
+  let mapping = match document.as_object() {
+    Some(mapping) => mapping,
+    None => return vec![Diagnostic::error(path, "expected a mapping".to_string())],
+  };
+
+  let mut diagnostics: Vec<Diagnostic> = schema
+    .properties
+    .iter()
+    .flat_map(|(name, property)| {
+      let attribute_path = join_path(path, name);
+      match mapping.get(name) {
+        Some(value) => validate_property(property, schemas, value, &attribute_path),
+        None if property.required => vec![Diagnostic::error(&attribute_path, "missing required attribute".to_string())],
+        None => vec![],
+      }
+    })
+    .collect();
+
+  diagnostics.extend(mapping.keys().filter(|key| !schema.properties.contains_key(*key)).map(|key| {
+    Diagnostic::warning(&join_path(path, key), "unexpected attribute".to_string())
+  }));
+
+  diagnostics
+}
+
+fn validate_group(schema: &Schema, schemas: &HashMap<String, Schema>, document: &Value, path: &str) -> Vec<Diagnostic> {
+  let matches = schema
+    .group_members
+    .iter()
+    .filter(|member| {
+      schemas.get(*member).is_some_and(|member_schema| {
+        !validate_against_schema(member_schema, schemas, document, path)
+          .iter()
+          .any(|diagnostic| diagnostic.severity == Severity::Error)
+      })
+    })
+    .count();
+
+  if matches == 1 {
+    vec![]
+  } else {
+    vec![Diagnostic::error(
+      path,
+      format!("expected 1 matching schema in group `{}` but got {}", schema.schema_name, matches),
+    )]
+  }
+}
+
+fn validate_property(property: &Property, schemas: &HashMap<String, Schema>, value: &Value, path: &str) -> Vec<Diagnostic> {
+  // `convert_prop` sets `list` exactly when the type string starts with `[`,
+  // in which case `type_name` is already `ArrayOf`/`Tuple` (the grammar
+  // parsed the whole `[...]` form). Validating through `type_name` alone
+  // already walks the elements, so wrapping in another per-element check
+  // here would validate each element against the array type a second time.
+  match (&property.type_name, property.list) {
+    (PropertyType::ArrayOf(_) | PropertyType::Tuple(_), _) => validate_type(&property.type_name, schemas, value, path),
+
+    (_, true) => match value.as_array() {
+      Some(items) => items
+        .iter()
+        .enumerate()
+        .flat_map(|(index, item)| validate_type(&property.type_name, schemas, item, &format!("{}[{}]", path, index)))
+        .collect(),
+      None => vec![Diagnostic::error(path, "expected a list".to_string())],
+    },
+
+    (_, false) => validate_type(&property.type_name, schemas, value, path),
+  }
+}
+
+fn validate_type(property_type: &PropertyType, schemas: &HashMap<String, Schema>, value: &Value, path: &str) -> Vec<Diagnostic> {
+  match property_type {
+    PropertyType::Constant(expected) => match value.as_str() {
+      Some(actual) if actual == expected => vec![],
+      _ => vec![Diagnostic::error(path, format!("expected the constant `{}`", expected))],
+    },
+
+    PropertyType::OneOf(variants) => {
+      if variants.iter().any(|variant| validate_type(variant, schemas, value, path).is_empty()) {
+        vec![]
+      } else {
+        vec![Diagnostic::error(path, "value did not match any of the allowed types".to_string())]
+      }
+    }
+
+    PropertyType::ArrayOf(item_type) => match value.as_array() {
+      Some(items) => items
+        .iter()
+        .enumerate()
+        .flat_map(|(index, item)| validate_type(item_type, schemas, item, &format!("{}[{}]", path, index)))
+        .collect(),
+      None => vec![Diagnostic::error(path, "expected an array".to_string())],
+    },
+
+    PropertyType::Dict(_key_type, value_type) => match value.as_object() {
+      Some(mapping) => mapping
+        .iter()
+        .flat_map(|(key, item)| validate_type(value_type, schemas, item, &join_path(path, key)))
+        .collect(),
+      None => vec![Diagnostic::error(path, "expected a mapping".to_string())],
+    },
+
+    PropertyType::Ref(name) => {
+      let normalized = normalize_schema_name(name);
+      match schemas.get(&normalized) {
+        Some(target_schema) => validate_against_schema(target_schema, schemas, value, path),
+        None => vec![Diagnostic::error(path, format!("unknown schema reference `{}`", normalized))],
+      }
+    }
+
+    PropertyType::Tuple(item_types) => match value.as_array() {
+      Some(items) if items.len() == item_types.len() => item_types
+        .iter()
+        .zip(items.iter())
+        .enumerate()
+        .flat_map(|(index, (item_type, item))| validate_type(item_type, schemas, item, &format!("{}[{}]", path, index)))
+        .collect(),
+      Some(items) => vec![Diagnostic::error(
+        path,
+        format!("expected a tuple of {} elements but got {}", item_types.len(), items.len()),
+      )],
+      None => vec![Diagnostic::error(path, "expected an array".to_string())],
+    },
+
+    PropertyType::String => match value.as_str() {
+      Some(_) => vec![],
+      None => vec![Diagnostic::error(path, "expected a string".to_string())],
+    },
+
+    PropertyType::Number => match value.as_f64() {
+      Some(_) => vec![],
+      None => vec![Diagnostic::error(path, "expected a number".to_string())],
+    },
+
+    PropertyType::Boolean => match value.as_bool() {
+      Some(_) => vec![],
+      None => vec![Diagnostic::error(path, "expected a boolean".to_string())],
+    },
+
+    PropertyType::Int => match value.as_i64() {
+      Some(_) => vec![],
+      None => vec![Diagnostic::error(path, "expected an integer".to_string())],
+    },
+
+    PropertyType::Null => match value.is_null() {
+      true => vec![],
+      false => vec![Diagnostic::error(path, "expected null".to_string())],
+    },
+  }
+}
+
+fn join_path(base: &str, segment: &str) -> String {
+  if base.is_empty() {
+    segment.to_string()
+  } else {
+    format!("{}.{}", base, segment)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::HashMap;
+
+  use serde_json::json;
+
+  use super::*;
+  use crate::schema::types::Property;
+
+  fn required_string_property() -> Property {
+    Property {
+      required: true,
+      docs: String::new(),
+      type_name: PropertyType::String,
+      list: false,
+    }
+  }
+
+  #[test]
+  fn a_stray_attribute_warning_does_not_disqualify_an_otherwise_matching_group_member() {
+    let member = Schema {
+      schema_name: "member".to_string(),
+      properties: HashMap::from([("name".to_string(), required_string_property())]),
+      is_group_member: false,
+      group_members: vec![],
+    };
+
+    let group = Schema {
+      schema_name: "group".to_string(),
+      properties: HashMap::new(),
+      is_group_member: true,
+      group_members: vec!["member".to_string()],
+    };
+
+    let schemas = HashMap::from([("member".to_string(), member)]);
+
+    // Satisfies every required/typed field of `member`, but also carries an
+    // attribute `member` doesn't know about.
+    let document = json!({ "name": "a-step", "unexpected": true });
+
+    let diagnostics = validate_document(&group, &schemas, &document);
+
+    assert!(diagnostics.is_empty(), "expected no diagnostics, got {:?}", diagnostics);
+  }
+
+  #[test]
+  fn a_real_list_attribute_of_scalars_validates_without_double_counting_list_ness() {
+    // Mirrors how `convert_prop` represents a `[string]`-typed attribute:
+    // `list` is true and `type_name` is already the parsed array type.
+    let schema = Schema {
+      schema_name: "step".to_string(),
+      properties: HashMap::from([(
+        "inputs".to_string(),
+        Property {
+          required: true,
+          docs: String::new(),
+          type_name: PropertyType::ArrayOf(Box::new(PropertyType::String)),
+          list: true,
+        },
+      )]),
+      is_group_member: false,
+      group_members: vec![],
+    };
+
+    let document = json!({ "inputs": ["a", "b"] });
+
+    let diagnostics = validate_document(&schema, &HashMap::new(), &document);
+
+    assert!(diagnostics.is_empty(), "expected no diagnostics, got {:?}", diagnostics);
+  }
+}